@@ -3,18 +3,45 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tokio::fs;
 
+/// A user-configured external tool launcher (an IDE, a second editor, a
+/// terminal multiplexer session, or a custom script) beyond the hardcoded
+/// Finder/Terminal/VS Code/browser actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Launcher {
+    pub id: String,
+    pub name: String,
+    pub command: String,
+    /// Whitespace-separated argument template; each `{path}` token is
+    /// replaced with the project path.
+    pub args: String,
+}
+
+/// Default recursion depth used when scanning project roots.
+fn default_max_depth() -> usize {
+    3
+}
+
 /// Application settings persisted to ~/.organizeme/config.json.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppSettings {
+    /// Base directories to scan for projects. A repo nested under more than
+    /// one root is only reported once (deduplicated by canonical path).
     #[serde(default)]
-    pub projects_path: String,
+    pub roots: Vec<String>,
+    #[serde(default)]
+    pub launchers: Vec<Launcher>,
+    #[serde(default = "default_max_depth")]
+    pub max_depth: usize,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
-            projects_path: String::new(),
+            roots: Vec::new(),
+            launchers: Vec::new(),
+            max_depth: default_max_depth(),
         }
     }
 }
@@ -61,30 +88,47 @@ pub async fn save_app_settings(settings: &AppSettings) -> Result<()> {
     Ok(())
 }
 
-/// Returns the projects directory path: config → env → default.
-pub fn get_projects_path() -> String {
-    // Try reading config synchronously from a blocking context
+/// Reads settings synchronously from a blocking context, defaulting if the
+/// config file is missing or unreadable.
+fn read_settings_sync() -> AppSettings {
     let config_file = get_config_file();
-    if let Ok(content) = std::fs::read_to_string(&config_file) {
-        if let Ok(settings) = serde_json::from_str::<AppSettings>(&content) {
-            if !settings.projects_path.is_empty() {
-                return settings.projects_path;
-            }
-        }
+    std::fs::read_to_string(&config_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<AppSettings>(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the configured project roots: config → env → default. Falls back
+/// to a single root (the env var or `~/Documents/Projects`) when none are
+/// configured, matching the single-root behavior this replaced.
+pub fn get_project_roots() -> Vec<String> {
+    let settings = read_settings_sync();
+    if !settings.roots.is_empty() {
+        return settings.roots;
     }
 
-    // Fall back to env var
     if let Ok(path) = std::env::var("PROJECTS_PATH") {
-        return path;
+        return vec![path];
     }
 
-    // Fall back to default
     dirs::home_dir()
         .map(|h| {
-            h.join("Documents")
+            vec![h
+                .join("Documents")
                 .join("Projects")
                 .to_string_lossy()
-                .to_string()
+                .to_string()]
         })
         .unwrap_or_default()
 }
+
+/// Returns the first configured project root, used as the destination base
+/// when acquiring a new project (cloning).
+pub fn get_primary_root() -> String {
+    get_project_roots().into_iter().next().unwrap_or_default()
+}
+
+/// Returns the configured scan recursion depth.
+pub fn get_max_depth() -> usize {
+    read_settings_sync().max_depth
+}
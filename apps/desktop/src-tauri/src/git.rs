@@ -1,19 +1,102 @@
-use crate::scanner::{GitInfoData, Project};
-use git2::Repository;
-
-/// Converts a git remote URL (SSH or HTTPS) to an HTTPS URL for browser opening.
-fn convert_remote_url(url: &str) -> String {
-    if url.starts_with("git@") {
-        // git@github.com:user/repo.git -> https://github.com/user/repo
-        let url = url.strip_prefix("git@").unwrap_or(url);
-        let url = url.replace(':', "/");
-        let url = format!("https://{}", url);
-        url.trim_end_matches(".git").to_string()
+use crate::config;
+use crate::scanner::{self, GitInfoData, Project};
+use anyhow::Result;
+use git2::{Cred, CredentialType, FetchOptions, RemoteCallbacks, Repository};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::process::Command;
+use tokio::sync::Semaphore;
+
+/// A git remote URL parsed into its host/owner/repo parts, abstracting over
+/// scp-like (`user@host:path`), `ssh://`, and `https://` forms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteUrl {
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+}
+
+impl RemoteUrl {
+    /// Builds the HTTPS browse URL for this remote, dropping any SSH user or port.
+    pub fn browse_url(&self) -> String {
+        format!("https://{}/{}/{}", self.host, self.owner, self.repo)
+    }
+}
+
+impl std::fmt::Display for RemoteUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.browse_url())
+    }
+}
+
+/// Strips a leading `user@` (or `user:password@`) from an authority segment.
+fn strip_userinfo(authority: &str) -> &str {
+    authority.rsplit('@').next().unwrap_or(authority)
+}
+
+/// Strips a trailing `:port` from an authority segment, preserving bracketed
+/// IPv6 hosts (`[::1]:2222` -> `[::1]`).
+fn strip_port(authority: &str) -> String {
+    if let Some(rest) = authority.strip_prefix('[') {
+        if let Some(end) = rest.find(']') {
+            return authority[..=end + 1].to_string();
+        }
+    }
+    authority.split(':').next().unwrap_or(authority).to_string()
+}
+
+/// Splits a scheme-less remote into its `authority` and `path` parts.
+fn split_authority_path(rest: &str) -> Result<(String, String)> {
+    let (authority, path) = rest
+        .split_once('/')
+        .ok_or_else(|| anyhow::anyhow!("Remote URL is missing a path: {}", rest))?;
+    Ok((authority.to_string(), path.to_string()))
+}
+
+/// Parses a git remote URL (scp-like, `ssh://`, or `https://`) into a `RemoteUrl`.
+///
+/// Handles self-hosted instances with custom ports, GitLab-style nested
+/// subgroups, and SSH host aliases (which parse like any other hostname).
+pub fn parse_remote_url(url: &str) -> Result<RemoteUrl> {
+    let url = url.trim();
+
+    let (authority, path) = if let Some(rest) = url.strip_prefix("ssh://") {
+        split_authority_path(rest)?
+    } else if let Some(rest) = url.strip_prefix("https://") {
+        split_authority_path(rest)?
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        split_authority_path(rest)?
+    } else if !url.contains("://") && url.contains('@') && url.contains(':') {
+        // scp-like form: user@host:path
+        url.split_once(':')
+            .map(|(authority, path)| (authority.to_string(), path.to_string()))
+            .ok_or_else(|| anyhow::anyhow!("Malformed scp-like remote URL: {}", url))?
     } else {
-        url.trim_end_matches(".git").to_string()
+        return Err(anyhow::anyhow!("Unrecognized remote URL: {}", url));
+    };
+
+    let host = strip_port(strip_userinfo(&authority));
+
+    let path = path.trim_end_matches(".git").trim_matches('/');
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "Remote URL is missing an owner/repo path: {}",
+            url
+        ));
     }
+
+    let repo = segments[segments.len() - 1].to_string();
+    let owner = segments[..segments.len() - 1].join("/");
+
+    Ok(RemoteUrl { host, owner, repo })
 }
 
+/// A project with no activity for this long is considered `archived` rather
+/// than merely `stale`.
+const ARCHIVED_AFTER_DAYS: i64 = 365;
+
 /// Determines the project status based on git info and last modified date.
 pub fn determine_project_status(git_info: Option<&GitInfoData>, last_modified: &str) -> String {
     if let Some(info) = git_info {
@@ -38,6 +121,8 @@ pub fn determine_project_status(git_info: Option<&GitInfoData>, last_modified: &
             let days = (chrono::Utc::now() - date).num_days();
             if days <= 7 {
                 return "active".to_string();
+            } else if days > ARCHIVED_AFTER_DAYS {
+                return "archived".to_string();
             } else if days > 30 {
                 return "stale".to_string();
             }
@@ -51,6 +136,8 @@ pub fn determine_project_status(git_info: Option<&GitInfoData>, last_modified: &
         let days = (chrono::Utc::now() - date.with_timezone(&chrono::Utc)).num_days();
         if days <= 7 {
             return "active".to_string();
+        } else if days > ARCHIVED_AFTER_DAYS {
+            return "archived".to_string();
         } else if days > 30 {
             return "stale".to_string();
         }
@@ -144,7 +231,10 @@ fn get_last_commit_info(repo: &Repository) -> (Option<String>, Option<String>) {
     (date, message)
 }
 
-/// Enriches a list of projects with git information concurrently.
+/// Enriches a list of projects with git information (status and remote URL)
+/// concurrently. Populating `git_remote_url` here, rather than only on the
+/// single-project `get_project` path, lets `tags::auto_tag`'s host-based tag
+/// (`github`/`gitlab`) actually fire during bulk scan/list/search flows.
 pub async fn enrich_projects_with_git_info(projects: &mut Vec<Project>) {
     let futures: Vec<_> = projects
         .iter()
@@ -154,23 +244,29 @@ pub async fn enrich_projects_with_git_info(projects: &mut Vec<Project>) {
             async move {
                 let git_info = get_git_status(&path).await;
                 let status = determine_project_status(git_info.as_ref(), &last_modified);
-                (git_info, status)
+                let remote_url = get_git_remote_url(&path).await;
+                (git_info, status, remote_url)
             }
         })
         .collect();
 
     let results: Vec<_> = futures::future::join_all(futures).await;
 
-    for (project, (git_info, status)) in projects.iter_mut().zip(results) {
+    for (project, (git_info, status, remote_url)) in projects.iter_mut().zip(results) {
         if git_info.is_some() {
             project.git_info = git_info;
             project.status = status;
         }
+        if let Some(remote_url) = remote_url {
+            project.git_remote_url = Some(remote_url.to_string());
+        }
     }
 }
 
-/// Gets the git remote URL for a project directory asynchronously.
-pub async fn get_git_remote_url(project_path: &str) -> Option<String> {
+/// Gets the parsed git remote ("origin") for a project directory asynchronously.
+/// Internal callers (e.g. the clone feature) can inspect `host`/`owner`/`repo`
+/// directly; use `RemoteUrl::browse_url` (or `to_string`) for the HTTPS link.
+pub async fn get_git_remote_url(project_path: &str) -> Option<RemoteUrl> {
     let path = project_path.to_string();
 
     tokio::task::spawn_blocking(move || get_git_remote_url_sync(&path))
@@ -180,10 +276,336 @@ pub async fn get_git_remote_url(project_path: &str) -> Option<String> {
 }
 
 /// Synchronous implementation of git remote URL retrieval.
-fn get_git_remote_url_sync(project_path: &str) -> Option<String> {
+fn get_git_remote_url_sync(project_path: &str) -> Option<RemoteUrl> {
     let repo = Repository::open(project_path).ok()?;
     let remote = repo.find_remote("origin").ok()?;
     let url = remote.url()?.to_string();
 
-    Some(convert_remote_url(&url))
+    parse_remote_url(&url).ok()
+}
+
+/// A parsed "clone spec": enough to compute both the destination directory
+/// and the URL to hand to `git2::build::RepoBuilder::clone`. `clone_url` is
+/// the original spec (shorthand expanded to `https://github.com/...`, but an
+/// explicit `ssh://`/`git@` transport is left untouched) so auth against
+/// private repos still works.
+struct CloneSpec {
+    clone_url: String,
+    host: String,
+    owner: String,
+    repo: String,
+}
+
+/// Parses a repo spec (`git@host:owner/repo.git`, `https://host/owner/repo`,
+/// or shorthand `owner/repo`) into a `CloneSpec`, reusing `parse_remote_url`'s
+/// segment-joining so multi-segment owners (GitLab-style subgroups) are
+/// preserved instead of only keeping the last path segment.
+fn parse_clone_spec(spec: &str) -> Result<CloneSpec> {
+    // Shorthand `owner/repo` specs have neither a scheme nor an scp-like
+    // `user@host:` prefix; normalize them to github.com before reusing
+    // `parse_remote_url`, which only recognizes scheme'd or scp-like URLs.
+    let normalized = if !spec.contains("://") && !(spec.contains('@') && spec.contains(':')) {
+        format!("https://github.com/{}", spec)
+    } else {
+        spec.to_string()
+    };
+
+    let remote = parse_remote_url(&normalized)?;
+
+    if !is_safe_clone_spec(&remote) {
+        return Err(anyhow::anyhow!(
+            "Remote URL resolves to an unsafe destination path: {}",
+            spec
+        ));
+    }
+
+    Ok(CloneSpec {
+        clone_url: normalized,
+        host: remote.host,
+        owner: remote.owner,
+        repo: remote.repo,
+    })
+}
+
+/// Checks that every path segment `clone_or_open` will join into `dest`
+/// (`host`, each `/`-separated part of `owner`, and `repo`) is a single plain
+/// path component, so a spec like `https://../../tmp/owner/repo` can't escape
+/// the configured projects root. Mirrors `remote::is_safe_project_name`,
+/// applied per-segment since `owner` may itself be a multi-segment GitLab
+/// subgroup path.
+fn is_safe_clone_spec(remote: &RemoteUrl) -> bool {
+    crate::remote::is_safe_project_name(&remote.host)
+        && crate::remote::is_safe_project_name(&remote.repo)
+        && remote.owner.split('/').all(crate::remote::is_safe_project_name)
+}
+
+/// Opens a project by repo spec (SSH, HTTPS, or shorthand `owner/repo`),
+/// cloning it into `<host>/<owner>/<repo>` under the projects path first if
+/// it isn't already present on disk. Authenticates the clone the same way
+/// `sync_all` authenticates a fetch (SSH agent / credential helper), so
+/// private repos the caller has access to clone successfully.
+pub async fn clone_or_open(spec: &str) -> Result<Project> {
+    let clone_spec = parse_clone_spec(spec)?;
+    let dest = PathBuf::from(config::get_primary_root())
+        .join(&clone_spec.host)
+        .join(&clone_spec.owner)
+        .join(&clone_spec.repo);
+
+    if !dest.exists() {
+        let dest_clone = dest.clone();
+        let clone_url = clone_spec.clone_url.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            if let Some(parent) = dest_clone.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            git2::build::RepoBuilder::new()
+                .fetch_options(fetch_options())
+                .clone(&clone_url, &dest_clone)?;
+            Ok(())
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Clone task panicked: {}", e))??;
+    }
+
+    let mut project = scanner::scan_project(&dest, &clone_spec.repo).await?;
+    project.git_info = get_git_status(&project.path).await;
+    project.status = determine_project_status(project.git_info.as_ref(), &project.last_modified);
+
+    Ok(project)
+}
+
+/// Checks that `clone_url` looks like an actual remote reference (`http(s)://`,
+/// `ssh://`, or an scp-like `git@host:path`) rather than something that could be
+/// interpreted as a `git clone` option when passed on the command line.
+fn is_safe_clone_url(clone_url: &str) -> bool {
+    if clone_url.starts_with('-') {
+        return false;
+    }
+
+    clone_url.starts_with("http://")
+        || clone_url.starts_with("https://")
+        || clone_url.starts_with("git@")
+        || clone_url.starts_with("ssh://")
+}
+
+/// Clones `clone_url` into `dest` by shelling out to `git clone` rather than
+/// libgit2, so it picks up the system git config and credential helpers.
+/// Rejects `clone_url` values that don't look like a real remote (e.g. an
+/// option-injection attempt like `--upload-pack=...`) before shelling out.
+pub async fn clone_via_cli(clone_url: &str, dest: &Path) -> Result<()> {
+    if !is_safe_clone_url(clone_url) {
+        return Err(anyhow::anyhow!("Refusing to clone unsafe URL: {}", clone_url));
+    }
+
+    let output = Command::new("git")
+        .arg("clone")
+        .arg("--")
+        .arg(clone_url)
+        .arg(dest)
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git clone failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Maximum number of `git fetch` operations allowed in flight at once.
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
+/// The result of syncing a single project in `sync_all`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncOutcome {
+    pub id: String,
+    pub fetched: bool,
+    pub ahead_by: usize,
+    pub behind_by: usize,
+    pub error: Option<String>,
+}
+
+/// Builds fetch options that authenticate via the SSH agent or the system's
+/// git credential helper, matching what a plain `git fetch` would use.
+fn fetch_options() -> FetchOptions<'static> {
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(|url, username_from_url, allowed_types| {
+        if allowed_types.contains(CredentialType::SSH_KEY) {
+            if let Some(username) = username_from_url {
+                if let Ok(cred) = Cred::ssh_key_from_agent(username) {
+                    return Ok(cred);
+                }
+            }
+        }
+
+        if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            if let Ok(config) = git2::Config::open_default() {
+                if let Ok(cred) = Cred::credential_helper(&config, url, username_from_url) {
+                    return Ok(cred);
+                }
+            }
+        }
+
+        Cred::default()
+    });
+
+    let mut options = FetchOptions::new();
+    options.remote_callbacks(callbacks);
+    options
+}
+
+/// Fetches `origin` and recomputes ahead/behind for a single project.
+fn sync_project_sync(id: String, project_path: String) -> SyncOutcome {
+    let repo = match Repository::open(&project_path) {
+        Ok(repo) => repo,
+        Err(e) => {
+            return SyncOutcome {
+                id,
+                fetched: false,
+                ahead_by: 0,
+                behind_by: 0,
+                error: Some(format!("Failed to open repository: {}", e)),
+            };
+        }
+    };
+
+    let mut remote = match repo.find_remote("origin") {
+        Ok(remote) => remote,
+        Err(e) => {
+            return SyncOutcome {
+                id,
+                fetched: false,
+                ahead_by: 0,
+                behind_by: 0,
+                error: Some(format!("No \"origin\" remote: {}", e)),
+            };
+        }
+    };
+
+    let fetch_error = remote
+        .fetch(&[] as &[&str], Some(&mut fetch_options()), None)
+        .err()
+        .map(|e| format!("Fetch failed: {}", e));
+    let fetched = fetch_error.is_none();
+
+    let head = match repo.head() {
+        Ok(head) => head,
+        Err(e) => {
+            return SyncOutcome {
+                id,
+                fetched,
+                ahead_by: 0,
+                behind_by: 0,
+                error: fetch_error.or_else(|| Some(format!("Failed to read HEAD: {}", e))),
+            };
+        }
+    };
+
+    let (ahead_by, behind_by) = get_ahead_behind(&repo, &head);
+
+    SyncOutcome {
+        id,
+        fetched,
+        ahead_by,
+        behind_by,
+        error: fetch_error,
+    }
+}
+
+/// Fetches every project that is a git repo and recomputes ahead/behind
+/// against the freshly updated upstream. Concurrency is bounded by a
+/// semaphore so this doesn't hammer the network, and a failure for one
+/// project is reported in its `SyncOutcome` rather than aborting the batch.
+pub async fn sync_all(projects: &[Project]) -> Vec<SyncOutcome> {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_FETCHES));
+
+    let futures = projects.iter().map(|project| {
+        let semaphore = semaphore.clone();
+        let id = project.id.clone();
+        let path = project.path.clone();
+
+        async move {
+            let _permit = semaphore.acquire().await.expect("semaphore closed");
+            let id_for_panic = id.clone();
+
+            tokio::task::spawn_blocking(move || sync_project_sync(id, path))
+                .await
+                .unwrap_or_else(|e| SyncOutcome {
+                    id: id_for_panic,
+                    fetched: false,
+                    ahead_by: 0,
+                    behind_by: 0,
+                    error: Some(format!("Sync task panicked: {}", e)),
+                })
+        }
+    });
+
+    futures::future::join_all(futures).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_https_url() {
+        let remote = parse_remote_url("https://github.com/owner/repo.git").unwrap();
+        assert_eq!(remote.host, "github.com");
+        assert_eq!(remote.owner, "owner");
+        assert_eq!(remote.repo, "repo");
+    }
+
+    #[test]
+    fn parses_scp_like_url() {
+        let remote = parse_remote_url("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(remote.host, "github.com");
+        assert_eq!(remote.owner, "owner");
+        assert_eq!(remote.repo, "repo");
+    }
+
+    #[test]
+    fn parses_nested_group_path() {
+        let remote = parse_remote_url("https://gitlab.com/myorg/backend/api.git").unwrap();
+        assert_eq!(remote.host, "gitlab.com");
+        assert_eq!(remote.owner, "myorg/backend");
+        assert_eq!(remote.repo, "api");
+    }
+
+    #[test]
+    fn strips_port_from_host() {
+        let remote = parse_remote_url("ssh://git@git.example.com:2222/owner/repo.git").unwrap();
+        assert_eq!(remote.host, "git.example.com");
+        assert_eq!(remote.owner, "owner");
+        assert_eq!(remote.repo, "repo");
+    }
+
+    #[test]
+    fn preserves_bracketed_ipv6_host() {
+        let remote = parse_remote_url("ssh://git@[::1]:2222/owner/repo.git").unwrap();
+        assert_eq!(remote.host, "[::1]");
+        assert_eq!(remote.owner, "owner");
+        assert_eq!(remote.repo, "repo");
+    }
+
+    #[test]
+    fn rejects_path_without_owner() {
+        assert!(parse_remote_url("https://github.com/repo.git").is_err());
+    }
+
+    #[test]
+    fn rejects_clone_spec_escaping_projects_root() {
+        assert!(parse_clone_spec("https://../../tmp/owner/repo").is_err());
+        assert!(parse_clone_spec("git@..:owner/repo").is_err());
+    }
+
+    #[test]
+    fn preserves_ssh_transport_for_clone_url() {
+        let spec = parse_clone_spec("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(spec.clone_url, "git@github.com:owner/repo.git");
+    }
 }
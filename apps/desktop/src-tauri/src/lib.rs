@@ -1,11 +1,33 @@
 mod config;
 mod git;
+mod matcher;
+mod metadata;
+mod remote;
 mod scanner;
 mod shell;
 mod tags;
+mod watcher;
+
+/// Re-exported as part of the crate's public matching API: a reusable
+/// name/path-only ranking primitive, independent of the tag-aware
+/// `search_projects` command's `fuzzy_search_with_tags`.
+pub use matcher::fuzzy_search;
 
 use scanner::ScanOptions;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tauri::{Emitter, Manager};
+use tokio::sync::Mutex;
+
+/// Debounce window for coalescing bursts of filesystem events into a single
+/// `projects-changed` emission per affected project.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Holds the live filesystem watches, one per configured root; dropping the
+/// inner `WatchHandle`s tears down the watches.
+#[derive(Default)]
+struct WatcherState(Mutex<Vec<watcher::WatchHandle>>);
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -38,25 +60,72 @@ pub struct TagResult {
     pub tags: Option<Vec<String>>,
 }
 
+/// Scans every configured project root and merges the results, deduplicating
+/// repos reachable from more than one root. Only fails if every root fails to
+/// scan; a single bad root is dropped silently so the rest still load.
+async fn scan_all_roots(options: &ScanOptions) -> Result<Vec<scanner::Project>, String> {
+    let roots = config::get_project_roots();
+    let mut projects = Vec::new();
+    let mut errors = Vec::new();
+
+    for root in &roots {
+        match scanner::scan_directory(root, options).await {
+            Ok(found) => projects.extend(found),
+            Err(e) => errors.push(format!("{}: {}", root, e)),
+        }
+    }
+
+    if !errors.is_empty() && errors.len() == roots.len() {
+        return Err(format!("Failed to scan any root: {}", errors.join("; ")));
+    }
+
+    dedupe_by_canonical_path(&mut projects);
+    Ok(projects)
+}
+
+/// Drops projects that resolve to a canonical path already seen, so a repo
+/// reachable from more than one configured root is only reported once.
+fn dedupe_by_canonical_path(projects: &mut Vec<scanner::Project>) {
+    let mut seen = HashSet::new();
+    projects.retain(|project| {
+        let key = std::fs::canonicalize(&project.path)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| project.path.clone());
+        seen.insert(key)
+    });
+}
+
 #[tauri::command]
 async fn get_projects() -> Result<ProjectListResponse, String> {
-    let projects_path = config::get_projects_path();
-    let options = ScanOptions::default();
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
 
-    match scanner::scan_directory(&projects_path, &options).await {
+    match scan_all_roots(&options).await {
         Ok(mut projects) => {
             // Enrich with git info
             git::enrich_projects_with_git_info(&mut projects).await;
 
             // Load tags for each project
             for project in &mut projects {
-                if let Ok(project_tags) = tags::get_project_tags(&project.id).await {
-                    project.tags = Some(project_tags);
+                tags::enrich_project_tags(project).await;
+
+                if let Ok(project_metadata) = metadata::get_project_metadata(&project.id).await {
+                    project.favorite = project_metadata.favorite;
+                    project.pinned = project_metadata.pinned;
+                    project.last_opened = project_metadata.last_opened;
                 }
             }
 
-            // Sort by last_modified descending
-            projects.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+            // Pinned projects float to the top, then most recently opened,
+            // then most recently modified.
+            projects.sort_by(|a, b| {
+                b.pinned
+                    .cmp(&a.pinned)
+                    .then_with(|| b.last_opened.cmp(&a.last_opened))
+                    .then_with(|| b.last_modified.cmp(&a.last_modified))
+            });
 
             let total = projects.len();
             Ok(ProjectListResponse {
@@ -71,10 +140,12 @@ async fn get_projects() -> Result<ProjectListResponse, String> {
 
 #[tauri::command]
 async fn get_project(id: String) -> Result<Option<scanner::Project>, String> {
-    let projects_path = config::get_projects_path();
-    let options = ScanOptions::default();
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
 
-    match scanner::scan_directory(&projects_path, &options).await {
+    match scan_all_roots(&options).await {
         Ok(projects) => {
             if let Some(mut project) = projects.into_iter().find(|p| p.id == id) {
                 // Enrich with git info
@@ -87,8 +158,13 @@ async fn get_project(id: String) -> Result<Option<scanner::Project>, String> {
                 }
 
                 // Load tags
-                if let Ok(project_tags) = tags::get_project_tags(&project.id).await {
-                    project.tags = Some(project_tags);
+                tags::enrich_project_tags(&mut project).await;
+
+                // Load metadata (favorite, pinned, last opened)
+                if let Ok(project_metadata) = metadata::get_project_metadata(&project.id).await {
+                    project.favorite = project_metadata.favorite;
+                    project.pinned = project_metadata.pinned;
+                    project.last_opened = project_metadata.last_opened;
                 }
 
                 // Load README
@@ -97,8 +173,8 @@ async fn get_project(id: String) -> Result<Option<scanner::Project>, String> {
                 }
 
                 // Get remote URL
-                if let Some(url) = git::get_git_remote_url(&project.path).await {
-                    project.git_remote_url = Some(url);
+                if let Some(remote) = git::get_git_remote_url(&project.path).await {
+                    project.git_remote_url = Some(remote.to_string());
                 }
 
                 Ok(Some(project))
@@ -112,10 +188,12 @@ async fn get_project(id: String) -> Result<Option<scanner::Project>, String> {
 
 #[tauri::command]
 async fn refresh_projects() -> RefreshResult {
-    let projects_path = config::get_projects_path();
-    let options = ScanOptions::default();
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
 
-    match scanner::scan_directory(&projects_path, &options).await {
+    match scan_all_roots(&options).await {
         Ok(mut projects) => {
             git::enrich_projects_with_git_info(&mut projects).await;
             let count = projects.len();
@@ -133,18 +211,46 @@ async fn refresh_projects() -> RefreshResult {
     }
 }
 
+/// Fetches every git project across all configured roots and recomputes
+/// ahead/behind against the freshly updated upstream, reporting a
+/// `SyncOutcome` per project.
+#[tauri::command]
+async fn sync_all_projects() -> Result<Vec<git::SyncOutcome>, String> {
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
+
+    let projects = scan_all_roots(&options).await?;
+    Ok(git::sync_all(&projects).await)
+}
+
+/// Stamps `last_opened` for the project at `path`, identified by its
+/// directory name the same way the scanner assigns project ids.
+async fn stamp_last_opened(path: &str) {
+    if let Some(name) = std::path::Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+    {
+        let _ = metadata::stamp_last_opened(&scanner::create_project_id(&name)).await;
+    }
+}
+
 #[tauri::command]
 async fn open_in_finder(path: String) -> AppResult {
+    stamp_last_opened(&path).await;
     shell::open_in_finder(&path).await
 }
 
 #[tauri::command]
 async fn open_in_terminal(path: String) -> AppResult {
+    stamp_last_opened(&path).await;
     shell::open_in_terminal(&path).await
 }
 
 #[tauri::command]
 async fn open_in_vscode(path: String) -> AppResult {
+    stamp_last_opened(&path).await;
     shell::open_in_vscode(&path).await
 }
 
@@ -153,6 +259,69 @@ async fn open_in_browser(url: String) -> AppResult {
     shell::open_in_browser(&url).await
 }
 
+#[tauri::command]
+async fn get_launchers() -> Result<Vec<config::Launcher>, String> {
+    let settings = config::get_app_settings().await.map_err(|e| e.to_string())?;
+    Ok(settings.launchers)
+}
+
+/// Adds (or replaces, by id) a user-configured launcher, persisting it to
+/// config.json so new tools can be added entirely from the app.
+#[tauri::command]
+async fn add_launcher(launcher: config::Launcher) -> Result<Vec<config::Launcher>, String> {
+    let mut settings = config::get_app_settings()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    settings.launchers.retain(|l| l.id != launcher.id);
+    settings.launchers.push(launcher);
+
+    config::save_app_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(settings.launchers)
+}
+
+/// Removes a user-configured launcher by id.
+#[tauri::command]
+async fn remove_launcher(launcher_id: String) -> Result<Vec<config::Launcher>, String> {
+    let mut settings = config::get_app_settings()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    settings.launchers.retain(|l| l.id != launcher_id);
+
+    config::save_app_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(settings.launchers)
+}
+
+#[tauri::command]
+async fn open_with_tool(project_path: String, tool_id: String) -> AppResult {
+    let settings = match config::get_app_settings().await {
+        Ok(settings) => settings,
+        Err(e) => {
+            return AppResult {
+                success: false,
+                message: format!("Failed to load settings: {}", e),
+            };
+        }
+    };
+
+    let Some(launcher) = settings.launchers.into_iter().find(|l| l.id == tool_id) else {
+        return AppResult {
+            success: false,
+            message: format!("Unknown launcher: {}", tool_id),
+        };
+    };
+
+    stamp_last_opened(&project_path).await;
+    shell::open_with_tool(&project_path, &launcher).await
+}
+
 #[tauri::command]
 async fn add_project_tag(project_id: String, tag: String) -> TagResult {
     match tags::add_tag_to_project(&project_id, &tag).await {
@@ -204,6 +373,26 @@ async fn get_all_tags() -> Result<Vec<String>, String> {
     tags::get_all_tags().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn set_project_favorite(
+    project_id: String,
+    favorite: bool,
+) -> Result<metadata::ProjectMetadata, String> {
+    metadata::set_favorite(&project_id, favorite)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn set_project_pinned(
+    project_id: String,
+    pinned: bool,
+) -> Result<metadata::ProjectMetadata, String> {
+    metadata::set_pinned(&project_id, pinned)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn get_readme_content(project_path: String) -> Result<Option<String>, String> {
     scanner::get_readme_content(&project_path)
@@ -213,7 +402,9 @@ async fn get_readme_content(project_path: String) -> Result<Option<String>, Stri
 
 #[tauri::command]
 async fn get_git_remote_url(project_path: String) -> Option<String> {
-    git::get_git_remote_url(&project_path).await
+    git::get_git_remote_url(&project_path)
+        .await
+        .map(|remote| remote.to_string())
 }
 
 #[tauri::command]
@@ -222,40 +413,262 @@ async fn get_app_settings() -> Result<config::AppSettings, String> {
 }
 
 #[tauri::command]
-async fn update_app_settings(projects_path: Option<String>) -> Result<config::AppSettings, String> {
+async fn update_app_settings(
+    app: tauri::AppHandle,
+    watcher_state: tauri::State<'_, WatcherState>,
+    add_root: Option<String>,
+    remove_root: Option<String>,
+    max_depth: Option<usize>,
+) -> Result<config::AppSettings, String> {
     let mut settings = config::get_app_settings()
         .await
         .map_err(|e| e.to_string())?;
 
-    if let Some(path) = projects_path {
-        settings.projects_path = path;
+    let mut roots_changed = false;
+
+    if let Some(root) = add_root {
+        if !settings.roots.contains(&root) {
+            settings.roots.push(root);
+            roots_changed = true;
+        }
+    }
+
+    if let Some(root) = remove_root {
+        let before = settings.roots.len();
+        settings.roots.retain(|r| *r != root);
+        roots_changed = roots_changed || settings.roots.len() != before;
+    }
+
+    if let Some(depth) = max_depth {
+        settings.max_depth = depth;
     }
 
     config::save_app_settings(&settings)
         .await
         .map_err(|e| e.to_string())?;
 
+    // If the watcher is currently running, restart it against the new roots.
+    if roots_changed && !watcher_state.0.lock().await.is_empty() {
+        begin_watching(&app, &watcher_state).await?;
+    }
+
     Ok(settings)
 }
 
+/// Returns the id of the project a `ProjectEvent` affects.
+fn watch_event_project_id(event: &watcher::ProjectEvent) -> String {
+    match event {
+        watcher::ProjectEvent::Added(project) | watcher::ProjectEvent::Modified(project) => {
+            project.id.clone()
+        }
+        watcher::ProjectEvent::Removed(id) => id.clone(),
+    }
+}
+
+/// Starts watching every configured project root, replacing any watches
+/// already owned by `watcher_state`. Spawns one debounce-consumer task per
+/// root, each emitting a `projects-changed` event per affected project id
+/// once a burst of filesystem events settles. Succeeds as long as at least
+/// one root could be watched.
+async fn begin_watching(
+    app: &tauri::AppHandle,
+    watcher_state: &WatcherState,
+) -> Result<(), String> {
+    let roots = config::get_project_roots();
+    let mut handles = Vec::new();
+    let mut errors = Vec::new();
+
+    for root in &roots {
+        match watcher::watch_projects(root).await {
+            Ok((events, handle)) => {
+                handles.push(handle);
+                spawn_debounce_consumer(app.clone(), events);
+            }
+            Err(e) => errors.push(format!("{}: {}", root, e)),
+        }
+    }
+
+    if handles.is_empty() && !errors.is_empty() {
+        return Err(format!("Failed to watch any root: {}", errors.join("; ")));
+    }
+
+    *watcher_state.0.lock().await = handles;
+    Ok(())
+}
+
+/// Spawns the debounce loop that coalesces a root's filesystem events into
+/// `projects-changed` emissions. Keeps only the latest event per project id
+/// during a burst, and emits the fully re-enriched `ProjectEvent` (git info
+/// and tags already loaded) rather than a bare id, so the frontend can apply
+/// it directly instead of re-scanning.
+fn spawn_debounce_consumer(
+    app: tauri::AppHandle,
+    mut events: tokio::sync::mpsc::Receiver<watcher::ProjectEvent>,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut pending: HashMap<String, watcher::ProjectEvent> = HashMap::new();
+
+        loop {
+            tokio::select! {
+                event = events.recv() => {
+                    match event {
+                        Some(event) => {
+                            pending.insert(watch_event_project_id(&event), event);
+                        }
+                        None => break,
+                    }
+                }
+                _ = tokio::time::sleep(WATCH_DEBOUNCE), if !pending.is_empty() => {
+                    for (_, event) in pending.drain() {
+                        let _ = app.emit("projects-changed", event);
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[tauri::command]
+async fn start_watching(
+    app: tauri::AppHandle,
+    watcher_state: tauri::State<'_, WatcherState>,
+) -> Result<(), String> {
+    begin_watching(&app, &watcher_state).await
+}
+
+#[tauri::command]
+async fn stop_watching(watcher_state: tauri::State<'_, WatcherState>) -> Result<(), String> {
+    watcher_state.0.lock().await.clear();
+    Ok(())
+}
+
+#[tauri::command]
+async fn search_projects(query: String) -> Result<Vec<scanner::Project>, String> {
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
+
+    let mut projects = scan_all_roots(&options).await?;
+
+    git::enrich_projects_with_git_info(&mut projects).await;
+
+    for project in &mut projects {
+        if let Ok(project_tags) = tags::get_project_tags(&project.id).await {
+            project.tags = Some(project_tags);
+        }
+
+        if let Ok(project_metadata) = metadata::get_project_metadata(&project.id).await {
+            project.favorite = project_metadata.favorite;
+            project.pinned = project_metadata.pinned;
+            project.last_opened = project_metadata.last_opened;
+        }
+    }
+
+    let mut ranked = matcher::fuzzy_search_with_tags(&query, &projects);
+    ranked.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| b.0.last_modified.cmp(&a.0.last_modified))
+    });
+
+    Ok(ranked.into_iter().map(|(project, _)| project.clone()).collect())
+}
+
+#[tauri::command]
+async fn list_remote_repos(
+    provider: String,
+    owner: String,
+) -> Result<Vec<remote::RemoteRepo>, String> {
+    let provider = match provider.to_lowercase().as_str() {
+        "github" => remote::Provider::GitHub,
+        "gitlab" => remote::Provider::GitLab,
+        other => return Err(format!("Unknown provider: {}", other)),
+    };
+
+    remote::list_remote_repos(provider, &owner)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn clone_project(clone_url: String, name: String) -> RefreshResult {
+    if let Err(e) = remote::clone_project(&clone_url, &name).await {
+        return RefreshResult {
+            success: false,
+            message: format!("Failed to clone {}: {}", name, e),
+            project_count: None,
+        };
+    }
+
+    let options = ScanOptions {
+        max_depth: config::get_max_depth(),
+        ..ScanOptions::default()
+    };
+
+    match scan_all_roots(&options).await {
+        Ok(projects) => RefreshResult {
+            success: true,
+            message: format!("Cloned {} into {}", name, config::get_primary_root()),
+            project_count: Some(projects.len()),
+        },
+        Err(e) => RefreshResult {
+            success: true,
+            message: format!("Cloned {} but failed to rescan: {}", name, e),
+            project_count: None,
+        },
+    }
+}
+
+/// Opens a project by repo spec (`git@host:owner/repo`, `https://host/owner/repo`,
+/// or shorthand `owner/repo`), cloning it under the primary root first if it
+/// isn't already present on disk.
+#[tauri::command]
+async fn open_project_by_spec(spec: String) -> Result<scanner::Project, String> {
+    git::clone_or_open(&spec).await.map_err(|e| e.to_string())
+}
+
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
+        .manage(WatcherState::default())
+        .setup(|app| {
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let watcher_state = app_handle.state::<WatcherState>();
+                if let Err(e) = begin_watching(&app_handle, &watcher_state).await {
+                    eprintln!("Failed to start project watcher: {}", e);
+                }
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_projects,
             get_project,
             refresh_projects,
+            sync_all_projects,
             open_in_finder,
             open_in_terminal,
             open_in_vscode,
             open_in_browser,
+            get_launchers,
+            add_launcher,
+            remove_launcher,
+            open_with_tool,
             add_project_tag,
             remove_project_tag,
             get_all_tags,
+            set_project_favorite,
+            set_project_pinned,
             get_readme_content,
             get_git_remote_url,
             get_app_settings,
             update_app_settings,
+            start_watching,
+            stop_watching,
+            search_projects,
+            list_remote_repos,
+            open_project_by_spec,
+            clone_project,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,172 @@
+use crate::scanner::Project;
+use std::path::Path;
+
+const MATCH_SCORE: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 15;
+const BOUNDARY_BONUS: i64 = 30;
+const LEADING_PENALTY: i64 = 1;
+
+/// Returns the string a project is matched against: its name, falling back
+/// to the last path component when the name is empty. Casing is preserved
+/// (matching itself is case-insensitive inside `score_match`) so a
+/// lower->upper transition in the original name (e.g. camelCase) can still
+/// be scored as a word boundary.
+fn candidate_string(project: &Project) -> String {
+    if !project.name.is_empty() {
+        project.name.clone()
+    } else {
+        Path::new(&project.path)
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Scores `candidate` as a subsequence match against `query` (assumed
+/// already lowercase). `candidate` keeps its original casing so a
+/// lower->upper transition (e.g. camelCase) is still detected as a word
+/// boundary, while the character comparison itself is case-insensitive.
+/// Returns `None` if `query` is not a subsequence of `candidate`.
+fn score_match(query: &str, candidate: &str) -> Option<i64> {
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let mut query_chars = query.chars().peekable();
+
+    let mut score = 0i64;
+    let mut last_match_index: Option<usize> = None;
+    let mut leading_unmatched = 0i64;
+
+    for (i, &c) in candidate_chars.iter().enumerate() {
+        let Some(&q) = query_chars.peek() else {
+            break;
+        };
+
+        let c_lower = c.to_lowercase().next().unwrap_or(c);
+        if c_lower != q {
+            if last_match_index.is_none() {
+                leading_unmatched += 1;
+            }
+            continue;
+        }
+
+        let is_word_boundary = i == 0
+            || matches!(candidate_chars[i - 1], '-' | '_' | '/')
+            || (candidate_chars[i - 1].is_lowercase() && c.is_uppercase());
+        let is_consecutive = i > 0 && last_match_index == Some(i - 1);
+
+        score += MATCH_SCORE;
+        if is_consecutive {
+            score += CONSECUTIVE_BONUS;
+        }
+        if is_word_boundary {
+            score += BOUNDARY_BONUS;
+        }
+
+        last_match_index = Some(i);
+        query_chars.next();
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    score -= leading_unmatched * LEADING_PENALTY;
+    Some(score)
+}
+
+/// Ranks `projects` against `query` using a subsequence fuzzy match over each
+/// project's name/path tail, similar to interactive fuzzy finders. A reusable
+/// ranking primitive for name/path-only matching; `fuzzy_search_with_tags`
+/// builds on this to also match against tags. Matches are sorted by
+/// descending score; an empty query matches everything with score 0,
+/// preserving input order.
+pub fn fuzzy_search<'a>(query: &str, projects: &'a [Project]) -> Vec<(&'a Project, i64)> {
+    let query = query.to_lowercase();
+
+    if query.is_empty() {
+        return projects.iter().map(|p| (p, 0)).collect();
+    }
+
+    let mut matches: Vec<(&Project, i64)> = projects
+        .iter()
+        .filter_map(|p| score_match(&query, &candidate_string(p)).map(|score| (p, score)))
+        .collect();
+
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches
+}
+
+/// Scores a project against `query` across its name/path tail and its tags,
+/// keeping the best-scoring candidate. Returns `None` if none match.
+fn best_score(query: &str, project: &Project) -> Option<i64> {
+    let mut best = score_match(query, &candidate_string(project));
+
+    for tag in project.tags.iter().flatten() {
+        if let Some(score) = score_match(query, tag) {
+            best = Some(best.map_or(score, |b| b.max(score)));
+        }
+    }
+
+    best
+}
+
+/// Like `fuzzy_search`, but also matches against each project's tags.
+/// Matches are sorted by descending score; an empty query matches everything
+/// with score 0, preserving input order.
+pub fn fuzzy_search_with_tags<'a>(query: &str, projects: &'a [Project]) -> Vec<(&'a Project, i64)> {
+    let query = query.to_lowercase();
+
+    if query.is_empty() {
+        return projects.iter().map(|p| (p, 0)).collect();
+    }
+
+    let mut matches: Vec<(&Project, i64)> = projects
+        .iter()
+        .filter_map(|p| best_score(&query, p).map(|score| (p, score)))
+        .collect();
+
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_non_subsequence() {
+        assert_eq!(score_match("xyz", "my-project"), None);
+    }
+
+    #[test]
+    fn word_boundary_beats_mid_word_match() {
+        let boundary = score_match("mp", "my-project").unwrap();
+        let mid_word = score_match("yp", "my-project").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn consecutive_matches_beat_scattered_ones() {
+        let consecutive = score_match("pro", "project").unwrap();
+        let scattered = score_match("pro", "pxrxoject").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn leading_unmatched_chars_are_penalized() {
+        let early = score_match("proj", "project").unwrap();
+        let late = score_match("proj", "my-project").unwrap();
+        assert!(early > late);
+    }
+
+    #[test]
+    fn camel_case_transition_counts_as_word_boundary() {
+        let boundary = score_match("p", "myProject").unwrap();
+        let mid_word = score_match("r", "myProject").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        assert!(score_match("myp", "MyProject").is_some());
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Per-project metadata persisted alongside tags: favorites, pinning, and
+/// last-opened tracking.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMetadata {
+    #[serde(default)]
+    pub favorite: bool,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub last_opened: Option<String>,
+}
+
+/// Maps project IDs to their metadata.
+type ProjectMetadataData = HashMap<String, ProjectMetadata>;
+
+/// Returns the path to the organizeMe configuration directory.
+fn get_config_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join(".organizeme")
+}
+
+/// Returns the path to the project metadata JSON file.
+fn get_metadata_file() -> PathBuf {
+    get_config_dir().join("project-metadata.json")
+}
+
+/// Ensures the configuration directory exists, creating it if necessary.
+async fn ensure_config_directory() -> Result<()> {
+    let config_dir = get_config_dir();
+    fs::create_dir_all(&config_dir).await?;
+    Ok(())
+}
+
+/// Loads all project metadata from the storage file.
+async fn load_all_metadata() -> Result<ProjectMetadataData> {
+    let metadata_file = get_metadata_file();
+
+    match fs::read_to_string(&metadata_file).await {
+        Ok(content) => {
+            let data: ProjectMetadataData = serde_json::from_str(&content)?;
+            Ok(data)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Saves all project metadata to the storage file.
+async fn save_all_metadata(data: &ProjectMetadataData) -> Result<()> {
+    ensure_config_directory().await?;
+    let metadata_file = get_metadata_file();
+    let content = serde_json::to_string_pretty(data)?;
+    fs::write(&metadata_file, content).await?;
+    Ok(())
+}
+
+/// Gets the metadata for a specific project, defaulting if none is stored.
+pub async fn get_project_metadata(project_id: &str) -> Result<ProjectMetadata> {
+    let all = load_all_metadata().await?;
+    Ok(all.get(project_id).cloned().unwrap_or_default())
+}
+
+/// Sets whether a project is favorited.
+pub async fn set_favorite(project_id: &str, favorite: bool) -> Result<ProjectMetadata> {
+    let mut all = load_all_metadata().await?;
+    let entry = all.entry(project_id.to_string()).or_default();
+    entry.favorite = favorite;
+    let updated = entry.clone();
+
+    save_all_metadata(&all).await?;
+    Ok(updated)
+}
+
+/// Sets whether a project is pinned.
+pub async fn set_pinned(project_id: &str, pinned: bool) -> Result<ProjectMetadata> {
+    let mut all = load_all_metadata().await?;
+    let entry = all.entry(project_id.to_string()).or_default();
+    entry.pinned = pinned;
+    let updated = entry.clone();
+
+    save_all_metadata(&all).await?;
+    Ok(updated)
+}
+
+/// Stamps a project's `last_opened` timestamp as now.
+pub async fn stamp_last_opened(project_id: &str) -> Result<()> {
+    let mut all = load_all_metadata().await?;
+    let entry = all.entry(project_id.to_string()).or_default();
+    entry.last_opened = Some(chrono::Utc::now().to_rfc3339());
+    save_all_metadata(&all).await
+}
@@ -0,0 +1,180 @@
+use crate::config;
+use crate::git;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A git hosting provider to list repositories from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    GitHub,
+    GitLab,
+}
+
+/// A repository returned by a remote provider's API, ready to clone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteRepo {
+    pub name: String,
+    pub full_name: String,
+    pub clone_url: String,
+    pub description: Option<String>,
+    pub private: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepo {
+    name: String,
+    full_name: String,
+    clone_url: String,
+    description: Option<String>,
+    private: bool,
+}
+
+impl From<GitHubRepo> for RemoteRepo {
+    fn from(repo: GitHubRepo) -> Self {
+        Self {
+            name: repo.name,
+            full_name: repo.full_name,
+            clone_url: repo.clone_url,
+            description: repo.description,
+            private: repo.private,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabRepo {
+    name: String,
+    path_with_namespace: String,
+    http_url_to_repo: String,
+    description: Option<String>,
+    visibility: String,
+}
+
+impl From<GitLabRepo> for RemoteRepo {
+    fn from(repo: GitLabRepo) -> Self {
+        Self {
+            name: repo.name,
+            full_name: repo.path_with_namespace,
+            clone_url: repo.http_url_to_repo,
+            description: repo.description,
+            private: repo.visibility != "public",
+        }
+    }
+}
+
+/// Lists the repositories owned by `owner` on `provider` via its REST API.
+pub async fn list_remote_repos(provider: Provider, owner: &str) -> Result<Vec<RemoteRepo>> {
+    match provider {
+        Provider::GitHub => list_github_repos(owner).await,
+        Provider::GitLab => list_gitlab_repos(owner).await,
+    }
+}
+
+async fn list_github_repos(owner: &str) -> Result<Vec<RemoteRepo>> {
+    let url = format!("https://api.github.com/users/{}/repos?per_page=100", owner);
+
+    let repos: Vec<GitHubRepo> = reqwest::Client::new()
+        .get(&url)
+        .header("User-Agent", "organizeme")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(repos.into_iter().map(Into::into).collect())
+}
+
+async fn list_gitlab_repos(owner: &str) -> Result<Vec<RemoteRepo>> {
+    let url = format!(
+        "https://gitlab.com/api/v4/users/{}/projects?per_page=100",
+        owner
+    );
+
+    let repos: Vec<GitLabRepo> = reqwest::Client::new()
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(repos.into_iter().map(Into::into).collect())
+}
+
+/// Checks that `name` is a single plain path component, so it can't escape
+/// the destination root via an absolute path or `..` segments.
+pub(crate) fn is_safe_project_name(name: &str) -> bool {
+    !name.is_empty()
+        && matches!(
+            Path::new(name).components().collect::<Vec<_>>().as_slice(),
+            [std::path::Component::Normal(_)]
+        )
+}
+
+/// Clones `clone_url` into `<projects_path>/<name>`, skipping the clone if
+/// the destination already exists so the project just appears via the
+/// normal scanner afterward.
+pub async fn clone_project(clone_url: &str, name: &str) -> Result<()> {
+    if !is_safe_project_name(name) {
+        return Err(anyhow::anyhow!("Invalid project name: {}", name));
+    }
+
+    let dest = PathBuf::from(config::get_primary_root()).join(name);
+
+    if dest.exists() {
+        return Ok(());
+    }
+
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    git::clone_via_cli(clone_url, &dest).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_github_repo_private_flag_through() {
+        let repo = GitHubRepo {
+            name: "repo".to_string(),
+            full_name: "owner/repo".to_string(),
+            clone_url: "https://github.com/owner/repo.git".to_string(),
+            description: Some("A repo".to_string()),
+            private: true,
+        };
+
+        let mapped: RemoteRepo = repo.into();
+        assert_eq!(mapped.name, "repo");
+        assert_eq!(mapped.full_name, "owner/repo");
+        assert_eq!(mapped.clone_url, "https://github.com/owner/repo.git");
+        assert_eq!(mapped.description.as_deref(), Some("A repo"));
+        assert!(mapped.private);
+    }
+
+    #[test]
+    fn maps_gitlab_visibility_to_private_flag() {
+        let make = |visibility: &str| GitLabRepo {
+            name: "api".to_string(),
+            path_with_namespace: "myorg/backend/api".to_string(),
+            http_url_to_repo: "https://gitlab.com/myorg/backend/api.git".to_string(),
+            description: None,
+            visibility: visibility.to_string(),
+        };
+        let private_repo = make("private");
+        let public_repo = make("public");
+
+        let mapped_private: RemoteRepo = private_repo.into();
+        let mapped_public: RemoteRepo = public_repo.into();
+
+        assert_eq!(mapped_private.full_name, "myorg/backend/api");
+        assert!(mapped_private.private);
+        assert!(!mapped_public.private);
+    }
+}
@@ -61,6 +61,9 @@ pub struct Project {
     pub id: String,
     pub name: String,
     pub path: String,
+    /// The configured root this project was discovered under.
+    #[serde(default)]
+    pub root: String,
     pub description: Option<String>,
     pub status: String,
     pub last_modified: String,
@@ -70,23 +73,40 @@ pub struct Project {
     pub readme_content: Option<String>,
     pub git_remote_url: Option<String>,
     pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub suggested_tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub favorite: bool,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub last_opened: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct ScanOptions {
     pub include_hidden: bool,
+    /// How many directory levels to descend below `projects_path`.
+    pub max_depth: usize,
+    /// Whether to descend into a discovered project looking for monorepo
+    /// child projects (e.g. packages/* workspaces).
+    pub detect_nested: bool,
 }
 
 impl Default for ScanOptions {
     fn default() -> Self {
         Self {
             include_hidden: false,
+            max_depth: 3,
+            detect_nested: true,
         }
     }
 }
 
 /// Creates a URL-safe identifier from a project name.
-fn create_project_id(name: &str) -> String {
+pub(crate) fn create_project_id(name: &str) -> String {
     let mapped: String = name
         .to_lowercase()
         .chars()
@@ -145,7 +165,7 @@ async fn get_project_description(dir_path: &Path) -> Option<String> {
 }
 
 /// Scans a single directory and creates a Project struct.
-async fn scan_project(dir_path: &Path, name: &str) -> Result<Project> {
+pub(crate) async fn scan_project(dir_path: &Path, name: &str) -> Result<Project> {
     let metadata = fs::metadata(dir_path).await?;
     let last_modified: chrono::DateTime<chrono::Utc> = metadata.modified()?.into();
     let project_id = create_project_id(name);
@@ -158,6 +178,7 @@ async fn scan_project(dir_path: &Path, name: &str) -> Result<Project> {
         id: project_id,
         name: name.to_string(),
         path: dir_path.to_string_lossy().to_string(),
+        root: String::new(),
         description,
         status: determine_initial_status(&last_modified),
         last_modified: last_modified.to_rfc3339(),
@@ -167,10 +188,16 @@ async fn scan_project(dir_path: &Path, name: &str) -> Result<Project> {
         readme_content: None,
         git_remote_url: None,
         tags: None,
+        parent_id: None,
+        suggested_tags: None,
+        favorite: false,
+        pinned: false,
+        last_opened: None,
     })
 }
 
-/// Scans a directory for project subdirectories.
+/// Scans a directory for project subdirectories, descending recursively
+/// (bounded by `options.max_depth`) when nested-project detection is enabled.
 pub async fn scan_directory(projects_path: &str, options: &ScanOptions) -> Result<Vec<Project>> {
     let path = Path::new(projects_path);
 
@@ -181,39 +208,86 @@ pub async fn scan_directory(projects_path: &str, options: &ScanOptions) -> Resul
         ));
     }
 
-    let mut entries = fs::read_dir(path).await?;
     let mut projects = Vec::new();
+    scan_directory_recursive(path, options, 0, None, &mut projects).await?;
 
-    while let Some(entry) = entries.next_entry().await? {
-        let file_type = entry.file_type().await?;
-        if !file_type.is_dir() {
-            continue;
-        }
+    for project in &mut projects {
+        project.root = projects_path.to_string();
+    }
 
-        let name = entry.file_name().to_string_lossy().to_string();
+    Ok(projects)
+}
 
-        if IGNORED_DIRECTORIES.contains(&name.as_str()) {
-            continue;
-        }
+/// Recursive worker behind `scan_directory`. At depth 0 every subdirectory is
+/// recorded (as scan_directory always has), with status "unknown" for ones
+/// that don't look like a project. At deeper levels, reached only when
+/// `detect_nested` is set, only recognized project directories are recorded
+/// as children (a repo's internal folders like `src/` aren't projects in
+/// their own right); descent stops once a project directory turns out to be
+/// a git repo itself, so vendored checkouts inside it aren't mistaken for
+/// nested projects.
+fn scan_directory_recursive<'a>(
+    dir_path: &'a Path,
+    options: &'a ScanOptions,
+    depth: usize,
+    parent_id: Option<&'a str>,
+    projects: &'a mut Vec<Project>,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + 'a>> {
+    Box::pin(async move {
+        let mut entries = fs::read_dir(dir_path).await?;
 
-        if !options.include_hidden && name.starts_with('.') {
-            continue;
-        }
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if !file_type.is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if IGNORED_DIRECTORIES.contains(&name.as_str()) {
+                continue;
+            }
+
+            if !options.include_hidden && name.starts_with('.') {
+                continue;
+            }
 
-        let full_path = entry.path();
+            let full_path = entry.path();
+            let is_project = is_project_directory(&full_path).await;
 
-        match scan_project(&full_path, &name).await {
-            Ok(mut project) => {
-                if !is_project_directory(&full_path).await {
+            if depth == 0 || is_project {
+                let mut project = match scan_project(&full_path, &name).await {
+                    Ok(project) => project,
+                    Err(_) => continue,
+                };
+
+                if !is_project {
                     project.status = "unknown".to_string();
                 }
+                project.parent_id = parent_id.map(|s| s.to_string());
+
+                let project_id = project.id.clone();
+                let is_git_repo = full_path.join(".git").exists();
                 projects.push(project);
+
+                if options.detect_nested && depth + 1 < options.max_depth && !is_git_repo {
+                    scan_directory_recursive(
+                        &full_path,
+                        options,
+                        depth + 1,
+                        Some(project_id.as_str()),
+                        projects,
+                    )
+                    .await?;
+                }
+            } else if options.detect_nested && depth + 1 < options.max_depth {
+                scan_directory_recursive(&full_path, options, depth + 1, parent_id, projects)
+                    .await?;
             }
-            Err(_) => continue,
         }
-    }
 
-    Ok(projects)
+        Ok(())
+    })
 }
 
 /// Reads README content from a project directory.
@@ -235,3 +309,109 @@ pub async fn get_readme_content(project_path: &str) -> Result<Option<String>> {
 
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an empty directory under the system temp dir, unique to this
+    /// test process, for `scan_directory`/`scan_directory_recursive` to walk.
+    fn make_temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "organizeme-scanner-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn stops_descending_once_a_project_dir_is_a_git_repo() {
+        let root = make_temp_dir("git-stop");
+        let repo = root.join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("vendor").join(".git")).unwrap();
+
+        let options = ScanOptions {
+            include_hidden: false,
+            max_depth: 3,
+            detect_nested: true,
+        };
+
+        let projects = scan_directory(root.to_str().unwrap(), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "repo");
+        assert!(projects[0].parent_id.is_none());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn links_nested_project_to_its_parent_id() {
+        let root = make_temp_dir("nested-parent");
+        let workspace = root.join("workspace");
+        let package = workspace.join("packages").join("core");
+        std::fs::create_dir_all(&package).unwrap();
+        std::fs::write(package.join("package.json"), "{}").unwrap();
+
+        let options = ScanOptions {
+            include_hidden: false,
+            max_depth: 3,
+            detect_nested: true,
+        };
+
+        let projects = scan_directory(root.to_str().unwrap(), &options)
+            .await
+            .unwrap();
+
+        let workspace_project = projects.iter().find(|p| p.name == "workspace").unwrap();
+        let core_project = projects.iter().find(|p| p.name == "core").unwrap();
+
+        assert_eq!(core_project.parent_id.as_deref(), Some(workspace_project.id.as_str()));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn respects_max_depth() {
+        let root = make_temp_dir("max-depth");
+        let deep = root
+            .join("a")
+            .join("packages")
+            .join("b")
+            .join("packages")
+            .join("c");
+        std::fs::create_dir_all(&deep).unwrap();
+        std::fs::write(root.join("a").join("package.json"), "{}").unwrap();
+        std::fs::write(
+            root.join("a").join("packages").join("b").join("package.json"),
+            "{}",
+        )
+        .unwrap();
+        std::fs::write(deep.join("package.json"), "{}").unwrap();
+
+        let options = ScanOptions {
+            include_hidden: false,
+            // Deep enough to reach "b" (nested one level below "a" through
+            // the non-project "packages" folder) but not "c" (one level
+            // further still).
+            max_depth: 3,
+            detect_nested: true,
+        };
+
+        let projects = scan_directory(root.to_str().unwrap(), &options)
+            .await
+            .unwrap();
+
+        assert!(projects.iter().any(|p| p.name == "a"));
+        assert!(projects.iter().any(|p| p.name == "b"));
+        assert!(!projects.iter().any(|p| p.name == "c"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,4 +1,6 @@
+use crate::config::Launcher;
 use crate::AppResult;
+use std::path::Path;
 use std::process::Command;
 
 /// Opens a path in the system file manager (Finder on macOS).
@@ -59,3 +61,44 @@ pub async fn open_in_browser(url: &str) -> AppResult {
         },
     }
 }
+
+/// Checks whether `command` resolves to an executable: either an existing
+/// path, or a name found on `PATH`.
+fn executable_exists(command: &str) -> bool {
+    let path = Path::new(command);
+    if command.contains('/') {
+        return path.exists();
+    }
+
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(command).exists()))
+        .unwrap_or(false)
+}
+
+/// Opens `path` with a user-configured `Launcher`, resolving its command/args
+/// template (`{path}` is replaced with the project path) and spawning it.
+pub async fn open_with_tool(path: &str, launcher: &Launcher) -> AppResult {
+    if !executable_exists(&launcher.command) {
+        return AppResult {
+            success: false,
+            message: format!("Executable not found: {}", launcher.command),
+        };
+    }
+
+    let args: Vec<String> = launcher
+        .args
+        .split_whitespace()
+        .map(|token| token.replace("{path}", path))
+        .collect();
+
+    match Command::new(&launcher.command).args(&args).spawn() {
+        Ok(_) => AppResult {
+            success: true,
+            message: format!("Opened {} with {}", path, launcher.name),
+        },
+        Err(e) => AppResult {
+            success: false,
+            message: format!("Failed to open with {}: {}", launcher.name, e),
+        },
+    }
+}
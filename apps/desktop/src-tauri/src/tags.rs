@@ -1,6 +1,7 @@
+use crate::scanner::Project;
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 /// Returns the path to the organizeMe configuration directory.
@@ -98,3 +99,195 @@ pub async fn remove_tag_from_project(project_id: &str, tag: &str) -> Result<()>
 
     save_project_tags(&all_tags).await
 }
+
+/// Indicator files mapped to the language/ecosystem tag they imply.
+const LANGUAGE_INDICATORS: &[(&str, &str)] = &[
+    ("Cargo.toml", "rust"),
+    ("package.json", "node"),
+    ("pyproject.toml", "python"),
+    ("go.mod", "go"),
+    ("pom.xml", "java"),
+    ("build.gradle", "java"),
+];
+
+/// `package.json` dependency names that imply a framework tag of the same name.
+const FRAMEWORK_DEPENDENCIES: &[&str] = &["next", "react", "vue", "svelte", "express", "nestjs"];
+
+/// Returns a `github`/`gitlab` tag based on the project's remote URL host.
+fn host_tag(remote_url: &str) -> Option<String> {
+    if remote_url.contains("github.com") {
+        Some("github".to_string())
+    } else if remote_url.contains("gitlab.com") {
+        Some("gitlab".to_string())
+    } else {
+        None
+    }
+}
+
+/// Scans `package.json` dependencies/devDependencies for known framework names.
+fn framework_hints(dir_path: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(dir_path.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+
+    let mut deps = serde_json::Map::new();
+    if let Some(d) = json.get("dependencies").and_then(|v| v.as_object()) {
+        deps.extend(d.clone());
+    }
+    if let Some(d) = json.get("devDependencies").and_then(|v| v.as_object()) {
+        deps.extend(d.clone());
+    }
+
+    FRAMEWORK_DEPENDENCIES
+        .iter()
+        .filter(|name| deps.contains_key(**name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Loads a project's stored tags and, from those, the not-yet-accepted
+/// `auto_tag` suggestions, setting `project.tags`/`project.suggested_tags`.
+/// Shared by the bulk project list/get commands and the file watcher's
+/// per-project refresh so both enrich tags the same way.
+pub async fn enrich_project_tags(project: &mut Project) {
+    if let Ok(project_tags) = get_project_tags(&project.id).await {
+        let suggested: Vec<String> = auto_tag(project)
+            .into_iter()
+            .filter(|t| !project_tags.contains(t))
+            .collect();
+        project.suggested_tags = (!suggested.is_empty()).then_some(suggested);
+        project.tags = Some(project_tags);
+    }
+}
+
+/// Proposes tags for a project from its own files and git metadata, without
+/// any user input: language/ecosystem from indicator files, activity from
+/// `determine_project_status`, the git host parsed from the remote URL, and
+/// top-level framework hints from `package.json` dependencies.
+pub fn auto_tag(project: &Project) -> Vec<String> {
+    let dir_path = Path::new(&project.path);
+    let mut tags = Vec::new();
+
+    for (indicator, tag) in LANGUAGE_INDICATORS {
+        if dir_path.join(indicator).exists() {
+            tags.push(tag.to_string());
+        }
+    }
+
+    match project.status.as_str() {
+        "stale" => tags.push("stale".to_string()),
+        "archived" => tags.push("archived".to_string()),
+        _ => {}
+    }
+
+    if let Some(remote_url) = &project.git_remote_url {
+        tags.extend(host_tag(remote_url));
+    }
+
+    tags.extend(framework_hints(dir_path));
+
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a minimal `Project` rooted at `path`, with every other field at
+    /// its "just scanned" default.
+    fn project_at(path: &Path) -> Project {
+        Project {
+            id: "example".to_string(),
+            name: "example".to_string(),
+            path: path.to_string_lossy().to_string(),
+            root: String::new(),
+            description: None,
+            status: "unknown".to_string(),
+            last_modified: String::new(),
+            git_info: None,
+            has_package_json: false,
+            has_readme: false,
+            readme_content: None,
+            git_remote_url: None,
+            tags: None,
+            parent_id: None,
+            suggested_tags: None,
+            favorite: false,
+            pinned: false,
+            last_opened: None,
+        }
+    }
+
+    /// Creates an empty directory under the system temp dir, unique to this
+    /// test process, for `auto_tag` to scan indicator files from.
+    fn make_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "organizeme-tags-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn detects_rust_indicator_file() {
+        let dir = make_temp_dir("rust-indicator");
+        std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"x\"").unwrap();
+
+        let tags = auto_tag(&project_at(&dir));
+
+        assert!(tags.contains(&"rust".to_string()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tags_stale_and_archived_status() {
+        let dir = make_temp_dir("status");
+
+        let mut project = project_at(&dir);
+        project.status = "stale".to_string();
+        assert_eq!(auto_tag(&project), vec!["stale".to_string()]);
+
+        project.status = "archived".to_string();
+        assert_eq!(auto_tag(&project), vec!["archived".to_string()]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tags_github_and_gitlab_host_from_remote_url() {
+        let dir = make_temp_dir("host");
+
+        let mut project = project_at(&dir);
+        project.git_remote_url = Some("https://github.com/owner/repo".to_string());
+        assert!(auto_tag(&project).contains(&"github".to_string()));
+
+        project.git_remote_url = Some("https://gitlab.com/owner/repo".to_string());
+        assert!(auto_tag(&project).contains(&"gitlab".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tags_framework_dependency_from_package_json() {
+        let dir = make_temp_dir("framework");
+        std::fs::write(
+            dir.join("package.json"),
+            r#"{"dependencies": {"react": "^18.0.0"}}"#,
+        )
+        .unwrap();
+
+        let mut project = project_at(&dir);
+        project.has_package_json = true;
+
+        assert!(auto_tag(&project).contains(&"react".to_string()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
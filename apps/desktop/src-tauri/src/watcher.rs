@@ -0,0 +1,165 @@
+use crate::git::{self, determine_project_status};
+use crate::metadata;
+use crate::scanner::{self, Project};
+use crate::tags;
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use tokio::sync::{mpsc, Mutex};
+
+/// Files inside a project directory whose changes should trigger a targeted
+/// refresh of just that project, rather than a full rescan.
+const TRACKED_FILES: &[&str] = &[".git/HEAD", ".git/index", "package.json", "README.md"];
+
+/// A change to the scanned project list, emitted by `watch_projects`. Carries
+/// the affected project fully re-enriched (git info, tags, and
+/// favorite/pinned/last-opened metadata) so a consumer can apply it directly
+/// instead of re-scanning.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "payload")]
+pub enum ProjectEvent {
+    Added(Project),
+    Removed(String),
+    Modified(Project),
+}
+
+/// Owns the underlying filesystem watcher; dropping it tears down the watch.
+/// Shared (rather than moved into the event-processing task) so that a
+/// `Create` event for a brand-new project directory can register that
+/// project's own tracked files for watching as it's discovered.
+pub struct WatchHandle {
+    _watcher: Arc<Mutex<RecommendedWatcher>>,
+}
+
+/// Watches `path` one level deep for project directories being added or
+/// removed, and watches each existing project's `.git/HEAD`, `.git/index`,
+/// and `package.json`/README files so commits, staged changes, and
+/// description edits trigger a targeted refresh of just that project. A
+/// project directory created after the watch starts has those of its tracked
+/// files that already exist when its `Create` event is observed registered
+/// then; tracked files that appear later are not watched.
+/// Dropping the returned `WatchHandle` tears down the watch: the forwarding
+/// task below holds only a `Weak` reference to the watcher, upgrading it just
+/// while handling one event, so once `WatchHandle` (the sole long-lived
+/// strong owner) is dropped, the `RecommendedWatcher`
+/// itself drops, unregistering the OS-level watch and closing `raw_tx`, and
+/// the task exits on its next iteration.
+pub async fn watch_projects(path: &str) -> Result<(mpsc::Receiver<ProjectEvent>, WatchHandle)> {
+    let root = PathBuf::from(path);
+    let root_str = path.to_string();
+    let (raw_tx, mut raw_rx) = mpsc::unbounded_channel::<notify::Result<Event>>();
+
+    let watcher = notify::recommended_watcher(move |res| {
+        let _ = raw_tx.send(res);
+    })?;
+    let watcher = Arc::new(Mutex::new(watcher));
+
+    watcher.lock().await.watch(&root, RecursiveMode::NonRecursive)?;
+
+    let mut entries = tokio::fs::read_dir(&root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.file_type().await?.is_dir() {
+            watch_project_files(&watcher, &entry.path()).await;
+        }
+    }
+
+    let (tx, rx) = mpsc::channel(64);
+    let root_for_task = root.clone();
+    let watcher_for_task = Arc::downgrade(&watcher);
+
+    tokio::spawn(async move {
+        while let Some(res) = raw_rx.recv().await {
+            let Ok(event) = res else { continue };
+            let Some(watcher) = watcher_for_task.upgrade() else {
+                break;
+            };
+            if let Some(project_event) =
+                classify_event(&root_for_task, &root_str, &watcher, &event).await
+            {
+                if tx.send(project_event).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok((rx, WatchHandle { _watcher: watcher }))
+}
+
+/// Adds per-file watches for a single project's tracked files.
+async fn watch_project_files(watcher: &Arc<Mutex<RecommendedWatcher>>, project_path: &Path) {
+    let mut watcher = watcher.lock().await;
+    for file in TRACKED_FILES {
+        let file_path = project_path.join(file);
+        if file_path.exists() {
+            let _ = watcher.watch(&file_path, RecursiveMode::NonRecursive);
+        }
+    }
+}
+
+/// Loads favorite/pinned/last-opened metadata onto `project`, the same way
+/// `get_projects`/`get_project`/`search_projects` do, so a `ProjectEvent`
+/// applied directly by a consumer doesn't clobber that state with the
+/// scanner's just-scanned defaults.
+async fn load_metadata(project: &mut Project) {
+    if let Ok(project_metadata) = metadata::get_project_metadata(&project.id).await {
+        project.favorite = project_metadata.favorite;
+        project.pinned = project_metadata.pinned;
+        project.last_opened = project_metadata.last_opened;
+    }
+}
+
+/// Turns a raw filesystem event into a `ProjectEvent`, re-scanning and
+/// re-enriching just the affected project with git info, tags, and
+/// favorite/pinned/last-opened metadata so consumers of `projects-changed`
+/// get a ready-to-display `Project` without having to re-scan everything
+/// themselves. `root_str` is stamped onto `Project.root` the same way
+/// `scanner::scan_directory` does for a full scan, so live updates agree
+/// with full-scan data on which configured root a project belongs to.
+async fn classify_event(
+    root: &Path,
+    root_str: &str,
+    watcher: &Arc<Mutex<RecommendedWatcher>>,
+    event: &Event,
+) -> Option<ProjectEvent> {
+    let touched_path = event.paths.first()?;
+
+    if touched_path.parent() == Some(root) {
+        let name = touched_path.file_name()?.to_string_lossy().to_string();
+        return match event.kind {
+            EventKind::Create(_) if touched_path.is_dir() => {
+                let mut project = scanner::scan_project(touched_path, &name).await.ok()?;
+                project.root = root_str.to_string();
+                watch_project_files(watcher, touched_path).await;
+                tags::enrich_project_tags(&mut project).await;
+                load_metadata(&mut project).await;
+                Some(ProjectEvent::Added(project))
+            }
+            EventKind::Remove(_) => Some(ProjectEvent::Removed(scanner::create_project_id(&name))),
+            _ => None,
+        };
+    }
+
+    let project_dir = find_project_dir(root, touched_path)?;
+    let name = project_dir.file_name()?.to_string_lossy().to_string();
+    let mut project = scanner::scan_project(&project_dir, &name).await.ok()?;
+    project.root = root_str.to_string();
+    let git_info = git::get_git_status(&project.path).await;
+    project.status = determine_project_status(git_info.as_ref(), &project.last_modified);
+    project.git_info = git_info;
+    tags::enrich_project_tags(&mut project).await;
+    load_metadata(&mut project).await;
+
+    Some(ProjectEvent::Modified(project))
+}
+
+/// Walks a touched path's ancestors back up to the project directory (the
+/// direct child of `root` that contains it).
+fn find_project_dir(root: &Path, touched_path: &Path) -> Option<PathBuf> {
+    touched_path
+        .ancestors()
+        .find(|ancestor| ancestor.parent() == Some(root))
+        .map(|p| p.to_path_buf())
+}